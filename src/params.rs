@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize as SerializeTrait;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::client::{Client, Response};
+
+/// A set of key-value pairs that can be attached to a Stripe object.
+pub type Metadata = HashMap<String, String>;
+
+/// A Stripe timestamp, represented as the number of seconds since the Unix epoch.
+pub type Timestamp = i64;
+
+/// A Stripe object that can be identified by its id.
+pub trait Object {
+    /// The canonical id type for this object.
+    type Id;
+
+    /// The id of the object.
+    fn id(&self) -> &Self::Id;
+
+    /// The object's type, as returned by the `object` field in the Stripe API.
+    fn object(&self) -> &'static str;
+}
+
+/// An id or, if `expand` was used, the object itself returned by a Stripe endpoint.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Expandable<T: Object> {
+    Id(T::Id),
+    Object(Box<T>),
+}
+
+/// The query parameter used to request that a response expand certain fields.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Expand<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl<'a> Expand<'a> {
+    /// Used as a `skip_serializing_if` predicate on the many
+    /// params structs that carry their own `expand: &[&str]` field.
+    pub fn is_empty(expand: &&'a [&'a str]) -> bool {
+        expand.is_empty()
+    }
+}
+
+/// A range filter, as used by the `created` parameter on list endpoints.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RangeQuery<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gt: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gte: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lt: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lte: Option<T>,
+}
+
+/// A single page of a cursor-paginated list of Stripe objects.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct List<T> {
+    pub data: Vec<T>,
+    pub has_more: bool,
+    pub total_count: Option<u64>,
+
+    /// The URL Stripe considers canonical for fetching the next page of this list.
+    ///
+    /// Absent or empty on lists that Stripe didn't mean to be paginated further;
+    /// callers must treat that as a terminal page rather than guessing a URL.
+    pub url: Option<String>,
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List { data: Vec::new(), has_more: false, total_count: None, url: None }
+    }
+}
+
+impl<T> List<T> {
+    fn next_page_url(&self) -> Option<&str> {
+        if !self.has_more {
+            return None;
+        }
+        self.url.as_deref().filter(|url| !url.is_empty())
+    }
+}
+
+impl<T: Object> List<T>
+where
+    T::Id: AsRef<str>,
+{
+    /// Works out the request for the next page, if there is one.
+    ///
+    /// `query` is whatever params the original `list` call was made with (so
+    /// filters like `limit`, `created`, or `expand` survive into later pages).
+    /// Its `starting_after` key, if any, is overwritten in place with the id
+    /// of this page's last element, per Stripe's cursor semantics — never
+    /// sent alongside the original, since a query string can't have two
+    /// winning values for the same key.
+    ///
+    /// Returns `None` once `has_more` is `false`, or once this page's `url` is
+    /// missing or empty — such a page is never assumed to live at `/v1/...`
+    /// plus the resource name, since that would silently paginate against a
+    /// URL Stripe never actually returned. It also returns `None` when this
+    /// page has no elements, since there is then no id to use as the cursor.
+    fn next_page_request<Q: SerializeTrait>(&self, query: &Q) -> Option<(&str, serde_json::Value)> {
+        let url = self.next_page_url()?;
+        let starting_after = self.data.last()?.id().as_ref().to_string();
+
+        let mut query = serde_json::to_value(query).unwrap_or(serde_json::Value::Null);
+        if let serde_json::Value::Object(ref mut map) = query {
+            map.insert("starting_after".to_string(), serde_json::Value::String(starting_after));
+        }
+        Some((url, query))
+    }
+}
+
+impl<T: Clone + DeserializeOwned + Object> List<T>
+where
+    T::Id: AsRef<str>,
+{
+    /// Fetches the next page of this list, carrying the original `query`
+    /// forward so filters like `limit` or `created` aren't dropped.
+    ///
+    /// See [`List::next_page_request`] for the terminal-page rules.
+    pub fn next<Q: SerializeTrait>(&self, client: &Client, query: &Q) -> Response<Option<List<T>>> {
+        match self.next_page_request(query) {
+            Some((url, query)) => client.get_query(url, &query).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Turns this page into a `ListPaginator` that lazily walks subsequent
+    /// pages via [`ListPaginator::next_page`], reusing `query` (the params the
+    /// original `list` call was made with) for every page after the first.
+    pub fn paginate<Q>(self, query: Q) -> ListPaginator<T, Q> {
+        ListPaginator { current: Some(self), query }
+    }
+}
+
+/// A cursor over the pages of a paginated list endpoint.
+///
+/// Walks pages in the order Stripe returns them, using each page's `url` and
+/// `has_more` fields, and stops once the final page is reached or a page's
+/// `url` turns out to be missing or empty.
+#[derive(Clone, Debug)]
+pub struct ListPaginator<T, Q> {
+    current: Option<List<T>>,
+    query: Q,
+}
+
+impl<T: Clone + DeserializeOwned + Object, Q: SerializeTrait> ListPaginator<T, Q>
+where
+    T::Id: AsRef<str>,
+{
+    /// Fetches and returns the next page, advancing the paginator.
+    ///
+    /// Returns `None` once the underlying list is exhausted, or once a page
+    /// can't be located (no `url`, or an empty one).
+    pub fn next_page(&mut self, client: &Client) -> Response<Option<List<T>>> {
+        let next = match &self.current {
+            Some(current) => current.next(client, &self.query)?,
+            None => None,
+        };
+        self.current = next.clone();
+        Ok(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct DummyId(String);
+
+    impl AsRef<str> for DummyId {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    #[derive(Clone)]
+    struct Dummy {
+        id: DummyId,
+    }
+
+    impl Object for Dummy {
+        type Id = DummyId;
+        fn id(&self) -> &Self::Id {
+            &self.id
+        }
+        fn object(&self) -> &'static str {
+            "dummy"
+        }
+    }
+
+    fn dummy(id: &str) -> Dummy {
+        Dummy { id: DummyId(id.to_string()) }
+    }
+
+    fn list(has_more: bool, url: Option<&str>, data: Vec<Dummy>) -> List<Dummy> {
+        List { data, has_more, total_count: None, url: url.map(str::to_string) }
+    }
+
+    #[test]
+    fn terminal_when_url_is_missing() {
+        let page = list(true, None, vec![dummy("it_1")]);
+        assert!(page.next_page_request(&()).is_none());
+    }
+
+    #[test]
+    fn terminal_when_url_is_empty() {
+        let page = list(true, Some(""), vec![dummy("it_1")]);
+        assert!(page.next_page_request(&()).is_none());
+    }
+
+    #[test]
+    fn terminal_when_has_more_is_false() {
+        let page = list(false, Some("/v1/dummies"), vec![dummy("it_1")]);
+        assert!(page.next_page_request(&()).is_none());
+    }
+
+    #[test]
+    fn walks_forward_using_the_last_element_as_the_cursor() {
+        let page = list(true, Some("/v1/dummies"), vec![dummy("it_1"), dummy("it_2")]);
+        let (url, query) = page.next_page_request(&serde_json::json!({})).expect("page should paginate");
+        assert_eq!(url, "/v1/dummies");
+        assert_eq!(query["starting_after"], "it_2");
+    }
+
+    #[test]
+    fn overwrites_rather_than_duplicates_an_existing_starting_after() {
+        let page = list(true, Some("/v1/dummies"), vec![dummy("it_1"), dummy("it_2")]);
+        let original_query = serde_json::json!({ "limit": 5, "starting_after": "cur_stale" });
+        let (_, query) = page.next_page_request(&original_query).expect("page should paginate");
+        assert_eq!(query["starting_after"], "it_2");
+        assert_eq!(query["limit"], 5);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::client::{Client, Response};
+use crate::resources::Currency;
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "Balance".
+///
+/// For more details see [https://stripe.com/docs/api/balance/balance_object](https://stripe.com/docs/api/balance/balance_object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Balance {
+    /// Funds that are available to be transferred or paid out, whether automatically by Stripe or explicitly via the [Transfers API](https://stripe.com/docs/api#transfers) or the [Payouts API](https://stripe.com/docs/api#payouts).
+    ///
+    /// The available balance for each currency and payment type can be found in the `source_types` property.
+    pub available: Vec<BalanceAmount>,
+
+    /// Funds held due to negative balances on connected Custom accounts.
+    ///
+    /// Only applicable if your account uses the [Custom accounts](https://stripe.com/docs/connect/custom-accounts) model for connected accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_reserved: Option<Vec<BalanceAmount>>,
+
+    /// Has the value `true` if the object exists in live mode or the value `false` if the object exists in test mode.
+    pub livemode: bool,
+
+    /// Funds that are not yet available in the balance, due to the 7-day rolling pay cycle.
+    ///
+    /// The pending balance for each currency and payment type can be found in the `source_types` property.
+    pub pending: Vec<BalanceAmount>,
+}
+
+impl Balance {
+    /// Retrieves the current account balance, based on the authentication that was used to make the request.
+    ///
+    /// For a sample request, see [Retrieve balance](https://stripe.com/docs/connect/bank-transfers#retrieving-the-balance).
+    pub fn retrieve(client: &Client) -> Response<Balance> {
+        client.get("/balance")
+    }
+}
+
+/// An amount of a given currency, broken down by the balance's underlying source types.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BalanceAmount {
+    /// Balance amount.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// Breakdown of balance by source types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_types: Option<BalanceAmountBySourceType>,
+}
+
+/// Breakdown of a `BalanceAmount` by the source type that funded it.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BalanceAmountBySourceType {
+    /// Amount for bank account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account: Option<i64>,
+
+    /// Amount for card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<i64>,
+}
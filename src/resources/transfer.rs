@@ -1,5 +1,6 @@
+use crate::client::{Client, Response};
 use crate::ids::TransferId;
-use crate::params::{Expand, Expandable, List, Metadata, Object, Timestamp};
+use crate::params::{Expand, Expandable, List, Metadata, Object, RangeQuery, Timestamp};
 use crate::resources::{Account, BalanceTransaction, Charge, Currency, TransferReversal};
 use serde_derive::{Deserialize, Serialize};
 
@@ -69,7 +70,7 @@ pub struct Transfer {
     ///
     /// One of `card` or `bank_account`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<TransferSourceType>,
 
     /// A string that identifies this transaction as part of a group.
     ///
@@ -79,6 +80,29 @@ pub struct Transfer {
 }
 
 impl Transfer {
+    /// To send funds from your Stripe account to a connected account, you create a new transfer object.
+    ///
+    /// Your [Stripe balance](https://stripe.com/docs/api/transfers/create#balance) must be able to cover the transfer amount, or you’ll receive an “Insufficient Funds” error.
+    pub fn create(client: &Client, params: CreateTransfer<'_>) -> Response<Transfer> {
+        client.post_form("/transfers", &params)
+    }
+
+    /// Retrieves the details of an existing transfer.
+    ///
+    /// Supply the unique transfer ID from either a transfer creation request or the transfer list, and Stripe will return the corresponding transfer information.
+    pub fn retrieve(client: &Client, id: &TransferId, expand: &[&str]) -> Response<Transfer> {
+        client.get_query(&format!("/transfers/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified transfer by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    ///
+    /// This request accepts only description and metadata as arguments.
+    pub fn update(client: &Client, id: &TransferId, params: UpdateTransfer<'_>) -> Response<Transfer> {
+        client.post_form(&format!("/transfers/{}", id), &params)
+    }
+
     /// Returns a list of existing transfers sent to connected accounts.
     ///
     /// The transfers are returned in sorted order, with the most recently created transfers appearing first.
@@ -97,33 +121,157 @@ impl Object for Transfer {
     }
 }
 
+/// The parameters for `Transfer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTransfer<'a> {
+    /// A positive integer in %s representing how much to transfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The ID of a connected Stripe account.
+    ///
+    /// See the Stripe Connect documentation for details.
+    pub destination: &'a str,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A set of key-value pairs that you can attach to a transfer object.
+    ///
+    /// It can be useful for storing additional information about the transfer in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// You can use this parameter to transfer funds from a charge before they are added to your available balance.
+    ///
+    /// A pending balance will transfer immediately but the funds will not become available until the original charge becomes available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_transaction: Option<&'a str>,
+
+    /// The source balance to use for this transfer.
+    ///
+    /// One of `card` or `bank_account`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<TransferSourceType>,
+
+    /// A string that identifies this transaction as part of a group.
+    ///
+    /// See the [Connect documentation](https://stripe.com/docs/connect/charges-transfers#grouping-transactions) for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+impl<'a> CreateTransfer<'a> {
+    pub fn new(currency: Currency, destination: &'a str) -> Self {
+        CreateTransfer {
+            amount: None,
+            currency,
+            description: None,
+            destination,
+            expand: &[],
+            metadata: None,
+            source_transaction: None,
+            source_type: None,
+            transfer_group: None,
+        }
+    }
+}
+
 /// The parameters for `Transfer::list`.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct TransferListParams<'a> {
-    #[serde(skip_deserializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     created: Option<RangeQuery<Timestamp>>,
 
     /// A cursor for use in pagination.
     ///
     /// `ending_before` is an object ID that defines your place in the list.
     /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-    #[serde(skip_deserializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     ending_before: Option<&'a TransferId>,
 
     /// Specifies which fields in the response should be expanded.
-    #[serde(skip_deserializing_if = "Expand::is_empty")]
+    #[serde(skip_serializing_if = "Expand::is_empty")]
     expand: &'a [&'a str],
 
     /// A limit on the number of objects to be returned.
     ///
     /// Limit can range between 1 and 100, and the default is 10.
-    #[serde(skip_deserializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     limit: Option<u64>,
 
     /// A cursor for use in pagination.
     ///
     /// `starting_after` is an object ID that defines your place in the list.
     /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-    #[serde(skip_deserializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     starting_after: Option<&'a TransferId>,
 }
+
+/// The parameters for `Transfer::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateTransfer<'a> {
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A set of key-value pairs that you can attach to a transfer object.
+    ///
+    /// It can be useful for storing additional information about the transfer in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// An enum representing the possible values of a `Transfer`'s `source_type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum TransferSourceType {
+    #[serde(rename = "alipay_account")]
+    AlipayAccount,
+    #[serde(rename = "bank_account")]
+    BankAccount,
+    #[serde(rename = "bitcoin_receiver")]
+    BitcoinReceiver,
+    #[serde(rename = "card")]
+    Card,
+}
+
+impl TransferSourceType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TransferSourceType::AlipayAccount => "alipay_account",
+            TransferSourceType::BankAccount => "bank_account",
+            TransferSourceType::BitcoinReceiver => "bitcoin_receiver",
+            TransferSourceType::Card => "card",
+        }
+    }
+}
+
+impl AsRef<str> for TransferSourceType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TransferSourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::client::{Client, Response};
+use crate::ids::{TransferId, TransferReversalId};
+use crate::params::{Expand, Expandable, List, Metadata, Object, Timestamp};
+use crate::resources::{BalanceTransaction, Currency, Refund, Transfer};
+use serde_derive::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "TransferReversal".
+///
+/// For more details see [https://stripe.com/docs/api/transfer_reversals/object](https://stripe.com/docs/api/transfer_reversals/object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TransferReversal {
+    /// Unique identifier for the object.
+    pub id: TransferReversalId,
+
+    /// Amount, in %s.
+    pub amount: i64,
+
+    /// Balance transaction that describes the impact on your account balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_transaction: Option<Expandable<BalanceTransaction>>,
+
+    /// Time that this record of the reversal was first created.
+    pub created: Timestamp,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// Linked payment refund for the transfer reversal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_refund: Option<Expandable<Refund>>,
+
+    /// A set of key-value pairs that you can attach to a transfer reversal object.
+    ///
+    /// It can be useful for storing additional information about the transfer reversal in a structured format.
+    pub metadata: Metadata,
+
+    /// Linked payment refund for the source transaction's refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_refund: Option<Expandable<Refund>>,
+
+    /// ID of the transfer that was reversed.
+    pub transfer: Expandable<Transfer>,
+}
+
+impl TransferReversal {
+    /// When you create a new reversal, you must specify a transfer to create it on.
+    ///
+    /// When reversing transfers, you can optionally reverse part of the transfer. You can do so as many times as you wish until the entire transfer has been reversed.
+    pub fn create(
+        client: &Client,
+        transfer: &TransferId,
+        params: CreateTransferReversal<'_>,
+    ) -> Response<TransferReversal> {
+        client.post_form(&format!("/transfers/{}/reversals", transfer), &params)
+    }
+
+    /// By default, you can see the 10 most recent reversals stored directly on the transfer object, but you can also retrieve details about a specific reversal stored on the transfer.
+    pub fn retrieve(
+        client: &Client,
+        transfer: &TransferId,
+        id: &TransferReversalId,
+        expand: &[&str],
+    ) -> Response<TransferReversal> {
+        client.get_query(&format!("/transfers/{}/reversals/{}", transfer, id), &Expand { expand })
+    }
+
+    /// Updates the specified reversal by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    ///
+    /// This request only accepts metadata and description as arguments.
+    pub fn update(
+        client: &Client,
+        transfer: &TransferId,
+        id: &TransferReversalId,
+        params: UpdateTransferReversal<'_>,
+    ) -> Response<TransferReversal> {
+        client.post_form(&format!("/transfers/{}/reversals/{}", transfer, id), &params)
+    }
+
+    /// You can see a list of the reversals belonging to a specific transfer.
+    ///
+    /// Note that the 10 most recent reversals are always available by default on the transfer object. If you need more than those 10, you can use this API method and the `starting_after` and `ending_before` parameters to page through additional reversals.
+    pub fn list(
+        client: &Client,
+        transfer: &TransferId,
+        params: ListTransferReversals<'_>,
+    ) -> Response<List<TransferReversal>> {
+        client.get_query(&format!("/transfers/{}/reversals", transfer), &params)
+    }
+}
+
+impl Object for TransferReversal {
+    type Id = TransferReversalId;
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+    fn object(&self) -> &'static str {
+        "transfer_reversal"
+    }
+}
+
+/// The parameters for `TransferReversal::create`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTransferReversal<'a> {
+    /// A positive integer in %s representing how much of this transfer to reverse.
+    ///
+    /// Can only reverse up to the unreversed amount remaining of the transfer. Partial transfer reversals are only allowed for transfers to Stripe Accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A set of key-value pairs that you can attach to a transfer reversal object.
+    ///
+    /// It can be useful for storing additional information about the transfer reversal in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Boolean indicating whether the application fee should be refunded when reversing this transfer.
+    ///
+    /// If a full transfer reversal is given, the full application fee will be refunded. Otherwise, the application fee will be refunded with an amount proportional to the amount of the transfer reversed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+}
+
+/// The parameters for `TransferReversal::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListTransferReversals<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a TransferReversalId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    /// For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a TransferReversalId>,
+}
+
+/// The parameters for `TransferReversal::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateTransferReversal<'a> {
+    /// An arbitrary string attached to the object.
+    ///
+    /// Often useful for displaying to users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A set of key-value pairs that you can attach to a transfer reversal object.
+    ///
+    /// It can be useful for storing additional information about the transfer reversal in a structured format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}